@@ -2,13 +2,15 @@
 
 use crate::err::{PyErr, PyResult};
 use crate::ffi;
+use crate::conversion::ToPyObject;
 use crate::instance::{Py, PyObjectWithGIL};
 use crate::object::PyObject;
 use crate::python::{Python, ToPyPointer};
 use crate::types::exceptions;
 use crate::types::PyObjectRef;
 use std::borrow::Cow;
-use std::os::raw::c_char;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
 use std::{mem, str};
 
 /// Represents a Python `string`.
@@ -17,6 +19,60 @@ pub struct PyString(PyObject);
 
 pyobject_native_type!(PyString, ffi::PyUnicode_Type, ffi::PyUnicode_Check);
 
+/// Represents a Python `string` in its canonical PEP 393 representation.
+///
+/// CPython stores ready unicode objects as one of three fixed-width encodings
+/// depending on the largest code point they contain. This enum borrows that
+/// native storage directly, without the UTF-8 round-trip performed by
+/// [`PyString::as_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PyStringData<'a> {
+    /// UCS-1 storage: every code point fits in a single byte (ASCII/Latin-1).
+    Ucs1(&'a [u8]),
+    /// UCS-2 storage: every code point fits in a `u16`.
+    Ucs2(&'a [u16]),
+    /// UCS-4 storage: the full Unicode range, one `u32` per code point.
+    Ucs4(&'a [u32]),
+}
+
+impl<'a> PyStringData<'a> {
+    /// Decode the native representation into a Rust string.
+    ///
+    /// Returns a `ValueError` if the string contains an unpaired surrogate,
+    /// which cannot be represented as a Rust `char`.
+    pub fn to_string(self) -> PyResult<Cow<'a, str>> {
+        match self {
+            // UCS1 stores raw code points (ASCII/Latin-1), not UTF-8, so each
+            // byte is a code point in 0x00..=0xFF and always a valid `char`.
+            PyStringData::Ucs1(data) => {
+                Ok(Cow::Owned(data.iter().map(|&b| b as char).collect()))
+            }
+            // Each u16 is a distinct code point, not a UTF-16 code unit, so
+            // surrogates must be rejected individually rather than paired up.
+            PyStringData::Ucs2(data) => match data
+                .iter()
+                .map(|&c| std::char::from_u32(u32::from(c)))
+                .collect::<Option<String>>()
+            {
+                Some(s) => Ok(Cow::Owned(s)),
+                None => Err(PyErr::new::<exceptions::ValueError, _>(
+                    "Unpaired surrogates found in UCS2 string.",
+                )),
+            },
+            PyStringData::Ucs4(data) => match data
+                .iter()
+                .map(|&c| std::char::from_u32(c))
+                .collect::<Option<String>>()
+            {
+                Some(s) => Ok(Cow::Owned(s)),
+                None => Err(PyErr::new::<exceptions::ValueError, _>(
+                    "Unpaired surrogates found in UCS4 string.",
+                )),
+            },
+        }
+    }
+}
+
 /// Represents a Python `byte` string.
 #[repr(transparent)]
 pub struct PyBytes(PyObject);
@@ -33,22 +89,66 @@ impl PyString {
         unsafe { Py::from_owned_ptr_or_panic(ffi::PyUnicode_FromStringAndSize(ptr, len)) }
     }
 
+    /// Creates a new single-character Python string from a Rust `char`.
+    ///
+    /// This is backed by `PyUnicode_FromOrdinal` and avoids building and
+    /// re-parsing a one-character `&str`, side-stepping the surrogate-validity
+    /// foot-guns of hand-rolled slices.
+    ///
+    /// Panics if out of memory.
+    pub fn from_ordinal(_py: Python, c: char) -> Py<PyString> {
+        unsafe {
+            Py::from_owned_ptr_or_panic(ffi::PyUnicode_FromOrdinal(c as c_int))
+        }
+    }
+
     pub fn from_object<'p>(
         src: &'p PyObjectRef,
         encoding: &str,
         errors: &str,
     ) -> PyResult<&'p PyString> {
+        let encoding = CString::new(encoding)?;
+        let errors = CString::new(errors)?;
         unsafe {
             src.py()
                 .from_owned_ptr_or_err::<PyString>(ffi::PyUnicode_FromEncodedObject(
                     src.as_ptr(),
-                    encoding.as_ptr() as *const c_char,
-                    errors.as_ptr() as *const c_char,
+                    encoding.as_ptr(),
+                    errors.as_ptr(),
+                ))
+        }
+    }
+
+    /// Encode the string into a `PyBytes` using the given codec.
+    ///
+    /// This is the inverse of [`from_object`](PyString::from_object): it runs
+    /// the named `encoding` with the given `errors` handler and returns the
+    /// resulting bytes, propagating `UnicodeEncodeError`/`LookupError` on
+    /// failure.
+    pub fn encode<'p>(
+        &'p self,
+        encoding: &str,
+        errors: &str,
+    ) -> PyResult<&'p PyBytes> {
+        let encoding = CString::new(encoding)?;
+        let errors = CString::new(errors)?;
+        unsafe {
+            self.py()
+                .from_owned_ptr_or_err::<PyBytes>(ffi::PyUnicode_AsEncodedString(
+                    self.as_ptr(),
+                    encoding.as_ptr(),
+                    errors.as_ptr(),
                 ))
         }
     }
 
     /// Get the Python string as a byte slice.
+    ///
+    /// `PyUnicode_AsUTF8AndSize` is not exported under the limited API before
+    /// Python 3.10, so the borrowing form is only available outside of that
+    /// configuration. Under an older limited-API build use
+    /// [`to_string`](PyString::to_string), which returns an owned copy.
+    #[cfg(any(not(Py_LIMITED_API), Py_3_10))]
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
         unsafe {
@@ -61,10 +161,62 @@ impl PyString {
         }
     }
 
+    /// Encode the string to an owned, UTF-8 encoded `PyBytes`.
+    ///
+    /// Used by the limited-API code paths, where the zero-copy UTF-8 cache is
+    /// not available.
+    #[cfg(all(Py_LIMITED_API, not(Py_3_10)))]
+    fn as_utf8_bytes(&self) -> PyResult<&PyBytes> {
+        unsafe {
+            self.py()
+                .from_owned_ptr_or_err::<PyBytes>(ffi::PyUnicode_AsEncodedString(
+                    self.as_ptr(),
+                    b"utf-8\0".as_ptr() as *const c_char,
+                    b"strict\0".as_ptr() as *const c_char,
+                ))
+        }
+    }
+
+    /// Get a view of the string's native PEP 393 representation.
+    ///
+    /// This borrows the interpreter's internal buffer directly, avoiding the
+    /// UTF-8 re-encoding that [`as_bytes`](PyString::as_bytes) performs. The
+    /// object is put into canonical ("ready") form first; an error is returned
+    /// if that fails.
+    ///
+    /// The underlying `PyUnicode_READY`/`PyUnicode_KIND`/`PyUnicode_DATA`
+    /// helpers are not part of the stable ABI, so this accessor is unavailable
+    /// under the limited API.
+    #[cfg(not(Py_LIMITED_API))]
+    pub fn data(&self) -> PyResult<PyStringData> {
+        let ptr = self.as_ptr();
+        unsafe {
+            if ffi::PyUnicode_READY(ptr) != 0 {
+                // PyUnicode_READY has set the exception on failure.
+                return Err(PyErr::fetch(self.py()));
+            }
+            let length = ffi::PyUnicode_GET_LENGTH(ptr) as usize;
+            let raw_data = ffi::PyUnicode_DATA(ptr);
+            match ffi::PyUnicode_KIND(ptr) {
+                ffi::PyUnicode_1BYTE_KIND => Ok(PyStringData::Ucs1(
+                    std::slice::from_raw_parts(raw_data as *const u8, length),
+                )),
+                ffi::PyUnicode_2BYTE_KIND => Ok(PyStringData::Ucs2(
+                    std::slice::from_raw_parts(raw_data as *const u16, length),
+                )),
+                ffi::PyUnicode_4BYTE_KIND => Ok(PyStringData::Ucs4(
+                    std::slice::from_raw_parts(raw_data as *const u32, length),
+                )),
+                _ => unreachable!("Unknown PyUnicode_KIND"),
+            }
+        }
+    }
+
     /// Convert the `PyString` into a Rust string.
     ///
     /// Returns a `UnicodeDecodeError` if the input is not valid unicode
     /// (containing unpaired surrogates).
+    #[cfg(any(not(Py_LIMITED_API), Py_3_10))]
     pub fn to_string(&self) -> PyResult<Cow<str>> {
         match std::str::from_utf8(self.as_bytes()) {
             Ok(s) => Ok(Cow::Borrowed(s)),
@@ -74,13 +226,52 @@ impl PyString {
         }
     }
 
+    /// Convert the `PyString` into a Rust string.
+    ///
+    /// Returns a `UnicodeEncodeError` if the input is not valid unicode
+    /// (containing unpaired surrogates), since it cannot be encoded to UTF-8.
+    ///
+    /// Under a limited-API build before Python 3.10 the string is first
+    /// encoded to an owned `PyBytes`, so the returned `Cow` is always owned.
+    #[cfg(all(Py_LIMITED_API, not(Py_3_10)))]
+    pub fn to_string(&self) -> PyResult<Cow<str>> {
+        let bytes = self.as_utf8_bytes()?;
+        match str::from_utf8(bytes.as_bytes()) {
+            Ok(s) => Ok(Cow::Owned(s.to_owned())),
+            Err(e) => Err(PyErr::from_instance(
+                exceptions::UnicodeDecodeError::new_utf8(self.py(), bytes.as_bytes(), e)?,
+            )),
+        }
+    }
+
     /// Convert the `PyString` into a Rust string.
     ///
     /// Unpaired surrogates invalid UTF-8 sequences are
     /// replaced with U+FFFD REPLACEMENT CHARACTER.
+    #[cfg(any(not(Py_LIMITED_API), Py_3_10))]
     pub fn to_string_lossy(&self) -> Cow<str> {
         String::from_utf8_lossy(self.as_bytes())
     }
+
+    /// Convert the `PyString` into a Rust string.
+    ///
+    /// Unpaired surrogates invalid UTF-8 sequences are
+    /// replaced with U+FFFD REPLACEMENT CHARACTER.
+    #[cfg(all(Py_LIMITED_API, not(Py_3_10)))]
+    pub fn to_string_lossy(&self) -> Cow<str> {
+        // `surrogatepass` writes surrogates out as invalid UTF-8 sequences,
+        // which `from_utf8_lossy` then replaces.
+        let bytes = self
+            .encode("utf-8", "surrogatepass")
+            .expect("PyUnicode_AsEncodedString with surrogatepass should not fail");
+        Cow::Owned(String::from_utf8_lossy(bytes.as_bytes()).into_owned())
+    }
+}
+
+impl ToPyObject for char {
+    fn to_object(&self, py: Python) -> PyObject {
+        PyString::from_ordinal(py, *self).into()
+    }
 }
 
 impl PyBytes {
@@ -94,6 +285,36 @@ impl PyBytes {
         unsafe { Py::from_owned_ptr_or_panic(ffi::PyBytes_FromStringAndSize(ptr, len)) }
     }
 
+    /// Creates a new Python `bytes` object of length `len`, filled by `f`.
+    ///
+    /// This allocates an uninitialized bytes object and hands its writable
+    /// internal buffer to the closure, avoiding the intermediate `Vec`
+    /// allocation and copy that [`new`](PyBytes::new) requires when the bytes
+    /// are generated rather than already in hand.
+    ///
+    /// The closure must fully initialize the buffer before the object escapes,
+    /// and the object must not be shared with Python until the closure returns.
+    /// If the closure returns an error the partially filled object is dropped
+    /// and the error is propagated.
+    pub fn new_with<F>(py: Python, len: usize, f: F) -> PyResult<Py<PyBytes>>
+    where
+        F: FnOnce(&mut [u8]) -> PyResult<()>,
+    {
+        unsafe {
+            let pyptr =
+                ffi::PyBytes_FromStringAndSize(std::ptr::null(), len as ffi::Py_ssize_t);
+            // Check for an allocation error and return it
+            let pybytes: Py<PyBytes> = Py::from_owned_ptr_or_err(py, pyptr)?;
+            let buffer = ffi::PyBytes_AsString(pyptr) as *mut u8;
+            debug_assert!(!buffer.is_null());
+            // The buffer is uninitialized; the closure is contracted to fully
+            // write it before the object escapes, so we hand it over as-is and
+            // avoid a redundant zeroing pass over large payloads.
+            f(std::slice::from_raw_parts_mut(buffer, len))?;
+            Ok(pybytes)
+        }
+    }
+
     /// Creates a new Python byte string object from raw pointer.
     ///
     /// Panics if out of memory.
@@ -118,8 +339,9 @@ impl PyBytes {
 
 #[cfg(test)]
 mod test {
-    use super::PyString;
+    use super::{PyBytes, PyString};
     use crate::conversion::{FromPyObject, PyTryFrom, ToPyObject};
+    use crate::types::exceptions;
     use crate::instance::AsPyRef;
     use crate::object::PyObject;
     use crate::python::Python;
@@ -146,6 +368,7 @@ mod test {
     }
 
     #[test]
+    #[cfg(any(not(Py_LIMITED_API), Py_3_10))]
     fn test_as_bytes() {
         let gil = Python::acquire_gil();
         let py = gil.python();
@@ -166,6 +389,39 @@ mod test {
         assert_eq!(Cow::Borrowed(s), py_string.to_string().unwrap());
     }
 
+    #[test]
+    fn test_bytes_new_with() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let py_bytes = PyBytes::new_with(py, 3, |b| {
+            b[0] = 1;
+            b[1] = 2;
+            b[2] = 3;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(py_bytes.as_ref(py).as_bytes(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_bytes_new_with_err() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let res = PyBytes::new_with(py, 3, |_| {
+            Err(exceptions::ValueError::py_err("Hello Crustaceans!"))
+        });
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_from_ordinal() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let obj: PyObject = PyString::from_ordinal(py, '🐈').into();
+        let py_string = <PyString as PyTryFrom>::try_from(obj.as_ref(py)).unwrap();
+        assert_eq!(Cow::Borrowed("🐈"), py_string.to_string().unwrap());
+    }
+
     #[test]
     fn test_to_string_unicode() {
         let gil = Python::acquire_gil();